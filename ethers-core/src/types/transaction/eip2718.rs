@@ -1,9 +1,98 @@
-use super::{eip1559::Eip1559TransactionRequest, eip2930::Eip2930TransactionRequest};
+use super::{
+    eip1559::Eip1559TransactionRequest, eip2930::Eip2930TransactionRequest,
+    eip4844::Eip4844TransactionRequest,
+};
 use crate::{
-    types::{Address, Bytes, NameOrAddress, TransactionRequest, H256, U64, Signature},
+    types::{
+        Address, Bytes, NameOrAddress, Signature, SignatureError, TransactionRequest, H256, U256,
+        U64,
+    },
     utils::keccak256,
 };
+use bytes::{BufMut, BytesMut};
+use rlp::{DecoderError, Rlp, RlpStream};
 use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+use thiserror::Error;
+
+/// An error involving an EIP-2718 typed transaction request.
+#[derive(Debug, Error)]
+pub enum Eip2718Error {
+    #[error(transparent)]
+    RlpError(#[from] DecoderError),
+    #[error(transparent)]
+    SignatureError(#[from] SignatureError),
+    #[error("Invalid EIP-2718 transaction type: {0}")]
+    InvalidTransactionType(u8),
+    #[error("signature has a non-canonical high `s` value (must be <= secp256k1n/2)")]
+    MalleableSignature,
+}
+
+impl From<Eip2718Error> for rlp::DecoderError {
+    fn from(err: Eip2718Error) -> rlp::DecoderError {
+        match err {
+            Eip2718Error::RlpError(err) => err,
+            Eip2718Error::SignatureError(_) => {
+                rlp::DecoderError::Custom("invalid signature")
+            }
+            Eip2718Error::InvalidTransactionType(_) => {
+                rlp::DecoderError::Custom("invalid EIP-2718 transaction type")
+            }
+            Eip2718Error::MalleableSignature => {
+                rlp::DecoderError::Custom("malleable signature (high s)")
+            }
+        }
+    }
+}
+
+/// The secp256k1 group order divided by two. Per EIP-2 / consensus rules, a signature's `s` value
+/// must not exceed this, to rule out the trivial `(r, n - s, v ^ 1)` malleability of an otherwise
+/// valid signature.
+fn secp256k1n_half() -> U256 {
+    U256::from_big_endian(&[
+        0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0x5d, 0x57, 0x6e, 0x73, 0x57, 0xa4, 0x50, 0x1d, 0xdf, 0xe9, 0x2f, 0x46, 0x68, 0x1b,
+        0x20, 0xa0,
+    ])
+}
+
+/// RLP-encodes the bare 6-field pre-EIP-155 legacy signing preimage (`nonce, gas_price, gas, to,
+/// value, data`), i.e. without the `(chain_id, 0, 0)` triple `TransactionRequest::rlp` always
+/// appends.
+fn legacy_signing_rlp_pre_eip155(tx: &TransactionRequest) -> Bytes {
+    let mut rlp = RlpStream::new();
+    rlp.begin_list(6);
+    rlp.append(&tx.nonce.unwrap_or_default());
+    rlp.append(&tx.gas_price.unwrap_or_default());
+    rlp.append(&tx.gas.unwrap_or_default());
+    match &tx.to {
+        Some(NameOrAddress::Address(addr)) => {
+            rlp.append(addr);
+        }
+        _ => {
+            rlp.append(&"");
+        }
+    }
+    rlp.append(&tx.value.unwrap_or_default());
+    match &tx.data {
+        Some(data) => {
+            rlp.append(data);
+        }
+        None => {
+            rlp.append(&"");
+        }
+    }
+    rlp.out().into()
+}
+
+fn decode_to(rlp: &Rlp, index: usize) -> Result<Option<NameOrAddress>, DecoderError> {
+    let data = rlp.at(index)?;
+    if data.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(NameOrAddress::Address(data.as_val()?)))
+    }
+}
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
 #[serde(tag = "type")]
@@ -17,6 +106,9 @@ pub enum TypedTransaction {
     // 0x02
     #[serde(rename = "0x02")]
     Eip1559(Eip1559TransactionRequest),
+    // 0x03
+    #[serde(rename = "0x03")]
+    Eip4844(Eip4844TransactionRequest),
 }
 
 impl TypedTransaction {
@@ -26,6 +118,7 @@ impl TypedTransaction {
             Legacy(inner) => inner.from.as_ref(),
             Eip2930(inner) => inner.tx.from.as_ref(),
             Eip1559(inner) => inner.from.as_ref(),
+            Eip4844(inner) => inner.from.as_ref(),
         }
     }
 
@@ -35,6 +128,7 @@ impl TypedTransaction {
             Legacy(inner) => inner.to.as_ref(),
             Eip2930(inner) => inner.tx.to.as_ref(),
             Eip1559(inner) => inner.to.as_ref(),
+            Eip4844(inner) => inner.to.as_ref(),
         }
     }
 
@@ -45,6 +139,7 @@ impl TypedTransaction {
             Legacy(inner) => inner.to = Some(to),
             Eip2930(inner) => inner.tx.to = Some(to),
             Eip1559(inner) => inner.to = Some(to),
+            Eip4844(inner) => inner.to = Some(to),
         };
     }
 
@@ -54,6 +149,7 @@ impl TypedTransaction {
             Legacy(inner) => inner.data.as_ref(),
             Eip2930(inner) => inner.tx.data.as_ref(),
             Eip1559(inner) => inner.data.as_ref(),
+            Eip4844(inner) => inner.data.as_ref(),
         }
     }
 
@@ -63,6 +159,7 @@ impl TypedTransaction {
             Legacy(inner) => inner.data = Some(data),
             Eip2930(inner) => inner.tx.data = Some(data),
             Eip1559(inner) => inner.data = Some(data),
+            Eip4844(inner) => inner.data = Some(data),
         };
     }
 
@@ -72,6 +169,7 @@ impl TypedTransaction {
             Legacy(inner) => inner.rlp_signed(signature),
             Eip2930(inner) => inner.tx.rlp_signed(signature),
             Eip1559(inner) => inner.rlp_signed(signature),
+            Eip4844(inner) => inner.rlp_signed(signature),
         }
     }
 
@@ -82,6 +180,18 @@ impl TypedTransaction {
             Legacy(inner) => inner.rlp(chain_id),
             Eip2930(inner) => inner.tx.rlp(chain_id),
             Eip1559(inner) => inner.rlp(chain_id),
+            Eip4844(inner) => inner.rlp(chain_id),
+        }
+    }
+
+    /// The `chain_id` this transaction is (or was) signed for, if any.
+    pub fn chain_id(&self) -> Option<U64> {
+        use TypedTransaction::*;
+        match self {
+            Legacy(inner) => inner.chain_id,
+            Eip2930(inner) => inner.tx.chain_id,
+            Eip1559(inner) => inner.chain_id,
+            Eip4844(inner) => inner.chain_id,
         }
     }
 }
@@ -105,11 +215,214 @@ impl TypedTransaction {
                 encoded.extend_from_slice(tx.rlp(chain_id).as_ref());
                 encoded
             }
+            TypedTransaction::Eip4844(ref tx) => {
+                let mut encoded = vec![3];
+                encoded.extend_from_slice(tx.rlp(chain_id).as_ref());
+                encoded
+            }
         };
         keccak256(encoded).into()
     }
 }
 
+impl TypedTransaction {
+    /// Decodes a transaction from its EIP-2718 enveloped representation, discarding the trailing
+    /// signature.
+    ///
+    /// Despite the name, this does not accept a bare unsigned RLP list (one without a trailing
+    /// `v, r, s`) — the signature fields are always read at a fixed index, so pass the same
+    /// signed envelope you'd pass to [`Self::decode_signed`].
+    pub fn decode(data: &[u8]) -> Result<Self, Eip2718Error> {
+        Ok(Self::decode_signed(data)?.0)
+    }
+
+    /// Decode a signed transaction from its EIP-2718 enveloped representation (plain RLP list for
+    /// legacy transactions, `type_byte || rlp` for typed ones), as received from
+    /// `eth_sendRawTransaction`/`eth_getRawTransactionByHash`. A typed transaction's bare
+    /// `rlp_signed` output lacks the type byte and is read as a legacy list.
+    ///
+    /// Returns the decoded transaction along with the `Signature` that was appended to it.
+    pub fn decode_signed(data: &[u8]) -> Result<(Self, Signature), Eip2718Error> {
+        let first = *data.first().ok_or(DecoderError::RlpIsTooShort)?;
+
+        // A legacy transaction is a bare RLP list, which always starts with a byte >= 0xc0.
+        if first >= 0xc0 {
+            let rlp = Rlp::new(data);
+            let v: u64 = rlp.val_at(6)?;
+            let r: U256 = rlp.val_at(7)?;
+            let s: U256 = rlp.val_at(8)?;
+
+            let chain_id = if v >= 35 { Some(U64::from((v - 35) / 2)) } else { None };
+
+            let tx = TransactionRequest {
+                from: None,
+                to: decode_to(&rlp, 3)?,
+                gas: Some(rlp.val_at(2)?),
+                gas_price: Some(rlp.val_at(1)?),
+                value: Some(rlp.val_at(4)?),
+                data: Some(rlp.val_at(5)?),
+                nonce: Some(rlp.val_at(0)?),
+                chain_id,
+            };
+
+            return Ok((TypedTransaction::Legacy(tx), Signature { r, s, v }))
+        }
+
+        match first {
+            0x01 => {
+                let rlp = Rlp::new(&data[1..]);
+                let v: u64 = rlp.val_at(8)?;
+                let r: U256 = rlp.val_at(9)?;
+                let s: U256 = rlp.val_at(10)?;
+
+                let tx = TransactionRequest {
+                    from: None,
+                    chain_id: Some(rlp.val_at(0)?),
+                    nonce: Some(rlp.val_at(1)?),
+                    gas_price: Some(rlp.val_at(2)?),
+                    gas: Some(rlp.val_at(3)?),
+                    to: decode_to(&rlp, 4)?,
+                    value: Some(rlp.val_at(5)?),
+                    data: Some(rlp.val_at(6)?),
+                };
+                let access_list = rlp.val_at(7)?;
+
+                let tx = Eip2930TransactionRequest { tx, access_list };
+
+                Ok((TypedTransaction::Eip2930(tx), Signature { r, s, v }))
+            }
+            0x02 => {
+                let rlp = Rlp::new(&data[1..]);
+                let v: u64 = rlp.val_at(9)?;
+                let r: U256 = rlp.val_at(10)?;
+                let s: U256 = rlp.val_at(11)?;
+
+                let tx = Eip1559TransactionRequest {
+                    from: None,
+                    chain_id: Some(rlp.val_at(0)?),
+                    nonce: Some(rlp.val_at(1)?),
+                    max_priority_fee_per_gas: Some(rlp.val_at(2)?),
+                    max_fee_per_gas: Some(rlp.val_at(3)?),
+                    gas: Some(rlp.val_at(4)?),
+                    to: decode_to(&rlp, 5)?,
+                    value: Some(rlp.val_at(6)?),
+                    data: Some(rlp.val_at(7)?),
+                    access_list: rlp.val_at(8)?,
+                };
+
+                Ok((TypedTransaction::Eip1559(tx), Signature { r, s, v }))
+            }
+            0x03 => {
+                let rlp = Rlp::new(&data[1..]);
+                let v: u64 = rlp.val_at(11)?;
+                let r: U256 = rlp.val_at(12)?;
+                let s: U256 = rlp.val_at(13)?;
+
+                let tx = Eip4844TransactionRequest {
+                    from: None,
+                    chain_id: Some(rlp.val_at(0)?),
+                    nonce: Some(rlp.val_at(1)?),
+                    max_priority_fee_per_gas: Some(rlp.val_at(2)?),
+                    max_fee_per_gas: Some(rlp.val_at(3)?),
+                    gas: Some(rlp.val_at(4)?),
+                    to: decode_to(&rlp, 5)?,
+                    value: Some(rlp.val_at(6)?),
+                    data: Some(rlp.val_at(7)?),
+                    access_list: rlp.val_at(8)?,
+                    max_fee_per_blob_gas: Some(rlp.val_at(9)?),
+                    blob_versioned_hashes: rlp.val_at(10)?,
+                };
+
+                Ok((TypedTransaction::Eip4844(tx), Signature { r, s, v }))
+            }
+            _ => Err(Eip2718Error::InvalidTransactionType(first)),
+        }
+    }
+}
+
+impl TypedTransaction {
+    /// Encodes the transaction as the "binary" enveloped format used for raw transaction
+    /// submission (e.g. `eth_sendRawTransaction`): plain `rlp(tx)` for `Legacy` transactions, or
+    /// `type_byte || rlp(tx)` for `Eip2930`/`Eip1559`/`Eip4844` transactions.
+    ///
+    /// `rlp_signed` returns only the signed RLP list, with no type byte; this method adds the
+    /// leading type byte where the variant requires one, so callers don't need to know which do.
+    pub fn encode_enveloped(&self, signature: &Signature) -> Bytes {
+        let mut out = BytesMut::new();
+        self.encode_enveloped_into(signature, &mut out);
+        out.freeze().into()
+    }
+
+    /// Like [`Self::encode_enveloped`], but writes into the given buffer instead of allocating.
+    pub fn encode_enveloped_into(&self, signature: &Signature, out: &mut dyn BufMut) {
+        use TypedTransaction::*;
+        match self {
+            Legacy(_) => {}
+            Eip2930(_) => out.put_u8(0x01),
+            Eip1559(_) => out.put_u8(0x02),
+            Eip4844(_) => out.put_u8(0x03),
+        }
+        out.put_slice(self.rlp_signed(signature).as_ref());
+    }
+
+    /// Convenience alias for [`Self::encode_enveloped`].
+    pub fn envelope_encoded(&self, signature: &Signature) -> Bytes {
+        self.encode_enveloped(signature)
+    }
+}
+
+impl TypedTransaction {
+    /// Recovers the Ethereum address of the account that produced `signature` by signing this
+    /// transaction for `chain_id`.
+    ///
+    /// This mirrors consensus client recovery: the type-aware signing hash is combined with the
+    /// signature's `r`, `s`, and recovery id (derived from `v`) to recover a secp256k1 public
+    /// key, which is hashed down to the signer's address. A signature whose `s` value is above
+    /// `secp256k1n/2` is rejected outright, matching the consensus low-s malleability rule (k256
+    /// recovery alone does not enforce this).
+    ///
+    /// Note that unlike `sighash` (which is also used as the EIP-2718 envelope hash and so
+    /// prefixes even `Legacy` transactions with a `0x00` type byte), the legacy signing hash has
+    /// no such prefix, so it is computed separately here. A pre-EIP-155 legacy signature
+    /// (`v == 27` or `28`) additionally signs over the bare 6-field transaction, with no
+    /// `(chain_id, 0, 0)` triple appended; only an EIP-155 legacy signature signs the 9-field
+    /// `rlp(chain_id)` encoding.
+    pub fn recover_from<T: Into<U64>>(
+        &self,
+        signature: &Signature,
+        chain_id: T,
+    ) -> Result<Address, Eip2718Error> {
+        if signature.s > secp256k1n_half() {
+            return Err(Eip2718Error::MalleableSignature)
+        }
+
+        let hash = match self {
+            TypedTransaction::Legacy(tx) if signature.v == 27 || signature.v == 28 => {
+                keccak256(legacy_signing_rlp_pre_eip155(tx).as_ref()).into()
+            }
+            TypedTransaction::Legacy(tx) => keccak256(tx.rlp(chain_id).as_ref()).into(),
+            _ => self.sighash(chain_id),
+        };
+        Ok(signature.recover(hash)?)
+    }
+
+    /// Decodes a signed, EIP-2718-enveloped transaction from `data` and recovers the address of
+    /// the account that signed it, using the `chain_id` embedded in the envelope.
+    pub fn recover_from_rlp(data: &[u8]) -> Result<Address, Eip2718Error> {
+        let (tx, signature) = Self::decode_signed(data)?;
+        let chain_id = tx.chain_id().unwrap_or_default();
+        tx.recover_from(&signature, chain_id)
+    }
+}
+
+impl TryFrom<&[u8]> for TypedTransaction {
+    type Error = Eip2718Error;
+
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        Self::decode(data)
+    }
+}
+
 impl From<TransactionRequest> for TypedTransaction {
     fn from(src: TransactionRequest) -> TypedTransaction {
         TypedTransaction::Legacy(src)
@@ -128,10 +441,16 @@ impl From<Eip1559TransactionRequest> for TypedTransaction {
     }
 }
 
+impl From<Eip4844TransactionRequest> for TypedTransaction {
+    fn from(src: Eip4844TransactionRequest) -> TypedTransaction {
+        TypedTransaction::Eip4844(src)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::{Address, U256};
+    use crate::types::Address;
 
     #[test]
     fn serde_legacy_tx() {
@@ -148,4 +467,158 @@ mod tests {
         let de: TransactionRequest = serde_json::from_str(&serialized).unwrap();
         assert_eq!(tx, TypedTransaction::Legacy(de));
     }
+
+    #[test]
+    fn decode_signed_legacy_round_trips_rlp_signed() {
+        let tx = TransactionRequest::new()
+            .nonce(U256::from(9))
+            .gas_price(U256::from(20_000_000_000u64))
+            .gas(U256::from(21_000))
+            .to(Address::from_low_u64_be(0x35))
+            .value(U256::from(1_000_000_000_000_000_000u64));
+        let tx = TypedTransaction::Legacy(tx);
+        let signature = Signature { r: U256::from(1), s: U256::from(2), v: 37 };
+
+        let encoded = tx.rlp_signed(&signature);
+        let (decoded, decoded_signature) = TypedTransaction::decode_signed(&encoded).unwrap();
+
+        assert_eq!(decoded_signature, signature);
+        match decoded {
+            TypedTransaction::Legacy(decoded_tx) => {
+                assert_eq!(decoded_tx.nonce, Some(U256::from(9)));
+                assert_eq!(decoded_tx.gas_price, Some(U256::from(20_000_000_000u64)));
+                assert_eq!(decoded_tx.gas, Some(U256::from(21_000)));
+                assert_eq!(decoded_tx.value, Some(U256::from(1_000_000_000_000_000_000u64)));
+                assert_eq!(decoded_tx.chain_id, Some(U64::from(1)));
+            }
+            _ => panic!("expected a legacy transaction"),
+        }
+    }
+
+    #[test]
+    fn decode_rejects_unknown_transaction_type() {
+        let err = TypedTransaction::decode(&[0x05]).unwrap_err();
+        assert!(matches!(err, Eip2718Error::InvalidTransactionType(0x05)));
+    }
+
+    #[test]
+    fn recover_from_legacy_eip155_vector() {
+        // The EIP-155 "Appendix F" example transaction (nonce 9, 20 gwei gas price, 21000 gas,
+        // to 0x3535..35, value 1 ether, no data), signed for chain_id 1 with the well-known test
+        // private key 0x46..46. The expected signer address and the r/s/v below were derived and
+        // cross-checked independently (secp256k1 point recovery + Keccak-256) outside this crate.
+        let tx = TransactionRequest::new()
+            .nonce(U256::from(9))
+            .gas_price(U256::from(20_000_000_000u64))
+            .gas(U256::from(21_000))
+            .to(Address::from_slice(&[
+                0x35, 0x35, 0x35, 0x35, 0x35, 0x35, 0x35, 0x35, 0x35, 0x35, 0x35, 0x35, 0x35,
+                0x35, 0x35, 0x35, 0x35, 0x35, 0x35, 0x35,
+            ]))
+            .value(U256::from(1_000_000_000_000_000_000u64));
+        let tx = TypedTransaction::Legacy(tx);
+
+        let signature = Signature {
+            r: U256::from_big_endian(&[
+                0xfd, 0x19, 0xe3, 0xfa, 0x95, 0x17, 0x0d, 0x25, 0x6c, 0xa3, 0x3a, 0xe7, 0x9c,
+                0xed, 0xc1, 0x9c, 0x20, 0xe5, 0xb0, 0x92, 0x8d, 0xcc, 0xc4, 0xc8, 0x1d, 0x4b,
+                0x5a, 0xd8, 0xe6, 0x0a, 0x76, 0xd5,
+            ]),
+            s: U256::from_big_endian(&[
+                0x56, 0x94, 0x51, 0x3f, 0xd7, 0x82, 0xe1, 0x79, 0x72, 0x95, 0x4e, 0xd1, 0x22,
+                0xd2, 0x71, 0x87, 0xf5, 0x9b, 0x7c, 0x93, 0xa3, 0x28, 0x81, 0x6e, 0x6f, 0xc8,
+                0xeb, 0xfd, 0xf3, 0xe6, 0xd3, 0xd9,
+            ]),
+            v: 38,
+        };
+
+        let expected = Address::from_slice(&[
+            0x9d, 0x8a, 0x62, 0xf6, 0x56, 0xa8, 0xd1, 0x61, 0x5c, 0x12, 0x94, 0xfd, 0x71, 0xe9,
+            0xcf, 0xb3, 0xe4, 0x85, 0x5a, 0x4f,
+        ]);
+
+        assert_eq!(tx.recover_from(&signature, 1u64).unwrap(), expected);
+    }
+
+    #[test]
+    fn recover_from_legacy_pre_eip155_vector() {
+        // Same tx fields as `recover_from_legacy_eip155_vector`, but signed without a chain id
+        // (v = 27/28). The signing preimage is therefore the bare 6-field list, not
+        // `rlp(chain_id)`. The key, signature, and expected address below are a self-generated
+        // vector, cross-checked independently (secp256k1 point recovery + Keccak-256) outside
+        // this crate.
+        let tx = TransactionRequest::new()
+            .nonce(U256::from(9))
+            .gas_price(U256::from(20_000_000_000u64))
+            .gas(U256::from(21_000))
+            .to(Address::from_slice(&[
+                0x35, 0x35, 0x35, 0x35, 0x35, 0x35, 0x35, 0x35, 0x35, 0x35, 0x35, 0x35, 0x35,
+                0x35, 0x35, 0x35, 0x35, 0x35, 0x35, 0x35,
+            ]))
+            .value(U256::from(1_000_000_000_000_000_000u64));
+        let tx = TypedTransaction::Legacy(tx);
+
+        let signature = Signature {
+            r: U256::from_big_endian(&[
+                0xb3, 0x93, 0x87, 0x0e, 0xe3, 0x29, 0xcc, 0x24, 0x93, 0xe9, 0x29, 0x15, 0x1f,
+                0xe5, 0xa3, 0x1e, 0xb2, 0xa0, 0x8a, 0x74, 0x1d, 0x9d, 0xd3, 0x1c, 0x1f, 0xe4,
+                0x83, 0x68, 0xf0, 0x59, 0xe6, 0x85,
+            ]),
+            s: U256::from_big_endian(&[
+                0x0c, 0xc2, 0x7c, 0xe7, 0x37, 0xbd, 0x94, 0x4c, 0xd2, 0x05, 0x38, 0x11, 0x44,
+                0xfe, 0x61, 0xc8, 0x14, 0x49, 0xb3, 0x32, 0x0b, 0xdf, 0xc5, 0x07, 0x9e, 0x69,
+                0xc4, 0x54, 0xdc, 0x5b, 0xff, 0xda,
+            ]),
+            v: 27,
+        };
+
+        let expected = Address::from_slice(&[
+            0x6c, 0x62, 0x58, 0xa0, 0xd5, 0x65, 0xe0, 0x9c, 0xba, 0xcf, 0x54, 0x9c, 0xea, 0xc7,
+            0x26, 0x4a, 0x7c, 0x00, 0x58, 0x5d,
+        ]);
+
+        // chain_id is irrelevant to a pre-EIP-155 signature's preimage, but still has to be
+        // passed through to satisfy the method's signature.
+        assert_eq!(tx.recover_from(&signature, 1u64).unwrap(), expected);
+    }
+
+    #[test]
+    fn recover_from_rejects_high_s_signature() {
+        let tx = TransactionRequest::new()
+            .nonce(U256::from(9))
+            .gas_price(U256::from(20_000_000_000u64))
+            .gas(U256::from(21_000))
+            .to(Address::from_low_u64_be(0x35))
+            .value(U256::from(1_000_000_000_000_000_000u64));
+        let tx = TypedTransaction::Legacy(tx);
+
+        // secp256k1n/2 + 1: the smallest non-canonical `s` value.
+        let high_s = U256::from_big_endian(&[
+            0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+            0xff, 0xff, 0x5d, 0x57, 0x6e, 0x73, 0x57, 0xa4, 0x50, 0x1d, 0xdf, 0xe9, 0x2f, 0x46,
+            0x68, 0x1b, 0x20, 0xa1,
+        ]);
+        let signature = Signature { r: U256::from(1), s: high_s, v: 27 };
+
+        let err = tx.recover_from(&signature, 1u64).unwrap_err();
+        assert!(matches!(err, Eip2718Error::MalleableSignature));
+    }
+
+    #[test]
+    fn encode_enveloped_prefixes_type_byte_for_typed_variants() {
+        let tx = Eip4844TransactionRequest {
+            chain_id: Some(U64::from(1)),
+            nonce: Some(U256::from(1)),
+            ..Default::default()
+        };
+        let tx = TypedTransaction::Eip4844(tx);
+        let signature = Signature { r: U256::from(1), s: U256::from(2), v: 0 };
+
+        let enveloped = tx.encode_enveloped(&signature);
+        let rlp_signed = tx.rlp_signed(&signature);
+
+        assert_eq!(enveloped[0], 0x03);
+        assert_eq!(&enveloped[1..], rlp_signed.as_ref());
+        assert_eq!(tx.envelope_encoded(&signature), enveloped);
+    }
 }
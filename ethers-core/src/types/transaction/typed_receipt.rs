@@ -0,0 +1,200 @@
+use super::eip2718::Eip2718Error;
+use crate::types::{Bloom, Bytes, Log, H256, U256, U64};
+use rlp::{DecoderError, Rlp, RlpStream};
+use serde::{Deserialize, Serialize};
+
+/// The pre-Byzantium state root, or the post-Byzantium status code, carried by a receipt.
+///
+/// Only one of the two is ever present on the wire: pre-Byzantium receipts RLP-encode a 32-byte
+/// state root as their first element, while post-Byzantium receipts RLP-encode a `{0,1}` status
+/// code in its place.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RootOrStatus {
+    Root(H256),
+    Status(U64),
+}
+
+/// The fields common to every [`TypedReceipt`] variant.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug, Default)]
+pub struct ReceiptData {
+    /// Post-Byzantium: `1` for success, `0` for failure.
+    pub status: Option<U64>,
+    /// Pre-Byzantium: the intermediate state root after the transaction executed.
+    pub post_state: Option<H256>,
+    pub cumulative_gas_used: U256,
+    pub logs_bloom: Bloom,
+    pub logs: Vec<Log>,
+}
+
+impl ReceiptData {
+    fn rlp_append(&self, s: &mut RlpStream) {
+        s.begin_list(4);
+        match (self.status, self.post_state) {
+            (Some(status), _) => {
+                s.append(&status);
+            }
+            (None, Some(root)) => {
+                s.append(&root);
+            }
+            (None, None) => {
+                s.append_empty_data();
+            }
+        }
+        s.append(&self.cumulative_gas_used);
+        s.append(&self.logs_bloom);
+        s.append_list(&self.logs);
+    }
+
+    fn rlp_decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+        let first = rlp.at(0)?;
+        // A pre-Byzantium state root is always 32 bytes; everything else (including the empty
+        // string, which is how RLP encodes a zero `U64`) is a post-Byzantium status code. This
+        // must not special-case the empty string as "absent" — `status = 0` (a failed
+        // transaction) encodes to the same empty string as a genuinely missing field, and the
+        // two are otherwise indistinguishable on the wire.
+        let (status, post_state) = if first.size() == 32 {
+            (None, Some(first.as_val()?))
+        } else {
+            (Some(first.as_val::<U64>()?), None)
+        };
+
+        Ok(Self {
+            status,
+            post_state,
+            cumulative_gas_used: rlp.val_at(1)?,
+            logs_bloom: rlp.val_at(2)?,
+            logs: rlp.list_at(3)?,
+        })
+    }
+}
+
+/// An EIP-2718 typed transaction receipt, tagged with the same `0x00`/`0x01`/`0x02` type bytes as
+/// the [`TypedTransaction`](super::eip2718::TypedTransaction) it is the receipt for.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+#[serde(tag = "type")]
+pub enum TypedReceipt {
+    #[serde(rename = "0x00")]
+    Legacy(ReceiptData),
+    #[serde(rename = "0x01")]
+    Eip2930(ReceiptData),
+    #[serde(rename = "0x02")]
+    Eip1559(ReceiptData),
+}
+
+impl TypedReceipt {
+    fn data(&self) -> &ReceiptData {
+        use TypedReceipt::*;
+        match self {
+            Legacy(data) | Eip2930(data) | Eip1559(data) => data,
+        }
+    }
+
+    /// The logs emitted by the transaction this receipt is for.
+    pub fn logs(&self) -> &[Log] {
+        &self.data().logs
+    }
+
+    /// The cumulative gas used in the block up to and including this transaction.
+    pub fn cumulative_gas_used(&self) -> U256 {
+        self.data().cumulative_gas_used
+    }
+
+    /// The pre-Byzantium state root, or the post-Byzantium status code, whichever this receipt
+    /// carries.
+    pub fn root_or_status(&self) -> Option<RootOrStatus> {
+        match (self.data().status, self.data().post_state) {
+            (Some(status), _) => Some(RootOrStatus::Status(status)),
+            (None, Some(root)) => Some(RootOrStatus::Root(root)),
+            (None, None) => None,
+        }
+    }
+
+    /// RLP-encodes the receipt in its EIP-2718 enveloped form: a plain RLP list for `Legacy`
+    /// receipts, or a leading type byte followed by an RLP list for `Eip2930`/`Eip1559` receipts.
+    pub fn rlp(&self) -> Bytes {
+        let mut s = RlpStream::new();
+        use TypedReceipt::*;
+        match self {
+            Legacy(data) => data.rlp_append(&mut s),
+            Eip2930(data) => {
+                s.append_raw(&[1], 0);
+                data.rlp_append(&mut s);
+            }
+            Eip1559(data) => {
+                s.append_raw(&[2], 0);
+                data.rlp_append(&mut s);
+            }
+        }
+        s.out().into()
+    }
+
+    /// Decodes a receipt from its EIP-2718 enveloped representation, as produced by [`Self::rlp`].
+    pub fn decode(data: &[u8]) -> Result<Self, Eip2718Error> {
+        let first = *data.first().ok_or(DecoderError::RlpIsTooShort)?;
+
+        if first >= 0xc0 {
+            let rlp = Rlp::new(data);
+            return Ok(TypedReceipt::Legacy(ReceiptData::rlp_decode(&rlp)?))
+        }
+
+        match first {
+            0x01 => Ok(TypedReceipt::Eip2930(ReceiptData::rlp_decode(&Rlp::new(&data[1..]))?)),
+            0x02 => Ok(TypedReceipt::Eip1559(ReceiptData::rlp_decode(&Rlp::new(&data[1..]))?)),
+            _ => Err(Eip2718Error::InvalidTransactionType(first)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Bloom, Log, H160};
+
+    fn test_data() -> ReceiptData {
+        ReceiptData {
+            status: Some(U64::from(1)),
+            post_state: None,
+            cumulative_gas_used: U256::from(21_000),
+            logs_bloom: Bloom::default(),
+            logs: vec![Log {
+                address: H160::zero(),
+                topics: vec![H256::zero()],
+                data: Bytes::from(vec![1, 2, 3]),
+                ..Default::default()
+            }],
+        }
+    }
+
+    #[test]
+    fn eip1559_receipt_round_trips_through_rlp() {
+        let receipt = TypedReceipt::Eip1559(test_data());
+
+        let encoded = receipt.rlp();
+        assert_eq!(encoded[0], 0x02);
+
+        let decoded = TypedReceipt::decode(&encoded).unwrap();
+        assert_eq!(decoded, receipt);
+    }
+
+    #[test]
+    fn legacy_receipt_round_trips_through_rlp() {
+        let receipt = TypedReceipt::Legacy(test_data());
+
+        let encoded = receipt.rlp();
+        let decoded = TypedReceipt::decode(&encoded).unwrap();
+        assert_eq!(decoded, receipt);
+    }
+
+    #[test]
+    fn status_zero_round_trips_as_failure_not_absent() {
+        let mut data = test_data();
+        data.status = Some(U64::zero());
+        let receipt = TypedReceipt::Eip2930(data);
+
+        let encoded = receipt.rlp();
+        let decoded = TypedReceipt::decode(&encoded).unwrap();
+
+        assert_eq!(decoded, receipt);
+        assert_eq!(decoded.root_or_status(), Some(RootOrStatus::Status(U64::zero())));
+    }
+}